@@ -1,7 +1,8 @@
 #![doc = include_str!("libdoc.md")]
 use core::{
+    cell::OnceCell,
     convert::{AsMut, AsRef},
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Shr},
 };
 
 /// Write function chains more concisely with the chained macro.
@@ -54,9 +55,35 @@ use core::{
 /// let result = chained!(>>> lazy, |x| x - 1);
 /// assert_eq!(69, result);
 /// ```
+/// If you're chaining fallible functions and want the first `Err` to short-circuit the rest
+///
+/// *Remember: use ? in the beginning*
+/// ```
+/// # use chained::*;
+/// let parsed = chained!(? Ok("42"), |s: &str| s.parse::<i32>().map_err(|_| "bad number"));
+/// assert_eq!(Ok(42), parsed.try_eval());
+/// ```
+/// If you want the chain to fork into several closures as its last stage
+///
+/// *Remember: separate the forked closures from the rest with `;` and wrap them in `fork[...]`*
+/// ```
+/// # use chained::*;
+/// let (sum, product) = chained!(4, |x| x + 1; fork[|x| x + x, |x| x * x]).eval();
+/// assert_eq!((10, 25), (sum, product));
+/// ```
 ///
 #[macro_export]
 macro_rules! chained {
+    ($val: expr $(, $fn: expr)* ; fork[$($f: expr),+ $(,)?]) => {
+        Link::new($val)
+            $(.chain($fn))*
+            .fork(($($f),+,))
+    };
+    ($val: expr $(=> $fn: expr)* ; fork[$($f: expr),+ $(,)?]) => {
+        Link::new($val)
+            $(.chain($fn))*
+            .fork(($($f),+,))
+    };
     ($val: expr, $($fn: expr),*) => {
         Link::new($val)
             $(.chain($fn))*
@@ -93,6 +120,14 @@ macro_rules! chained {
             $(.chain($fn))*
             .eval()
     };
+    (? $val: expr, $($fn: expr),*) => {
+        TryLink::new($val)
+            $(.try_chain($fn))*
+    };
+    (? $val: expr => $($fn: expr)=>*) => {
+        TryLink::new($val)
+            $(.try_chain($fn))*
+    };
 }
 
 /// The trait that is the heart and soul of this crate.
@@ -107,6 +142,46 @@ pub trait Chained {
     {
         Chain { val: self, fun }
     }
+
+    /// Feeds the upstream value into several independent closures and collects their outputs
+    /// into a tuple. Every closure but the last gets a clone of the value; the last one takes
+    /// it by move.
+    /// ```
+    /// # use chained::*;
+    /// let (sum, product) = Link::new(4)
+    ///     .fork((|x| x + x, |x| x * x))
+    ///     .eval();
+    /// assert_eq!((8, 16), (sum, product));
+    /// ```
+    fn fork<Fns>(self, funs: Fns) -> Fork<Self, Fns>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        Fns: ForkFns<Self::Item>,
+    {
+        Fork { val: self, funs }
+    }
+
+    /// Runs a side-effecting closure on a borrow of the intermediate value and passes the
+    /// value through unchanged, mirroring iterator [inspect][Iterator::inspect].
+    /// ```
+    /// # use chained::*;
+    /// let result = Link::new(10)
+    ///     .inspect(|x| assert_eq!(&10, x))
+    ///     .chain(|x| x + 1)
+    ///     .eval();
+    /// assert_eq!(11, result);
+    /// ```
+    fn inspect<F>(self, f: F) -> Chain<Self, impl FnOnce(Self::Item) -> Self::Item, Self::Item>
+    where
+        Self: Sized,
+        F: FnOnce(&Self::Item),
+    {
+        self.chain(move |item| {
+            f(&item);
+            item
+        })
+    }
 }
 
 /// The trait that helps you create a function chain on any type T by taking a fn/closure and returning a [Chain] type, which implements the [Chained] trait.
@@ -252,3 +327,318 @@ where
         (self.fun)(self.val.eval())
     }
 }
+
+/// A thin wrapper around a closure, needed to build chains with the `>>` operator.
+///
+/// Bare closures don't carry a nominal type of their own, so coherence rules won't let this
+/// crate implement [Shr] generically over any `FnOnce`. Wrapping a closure in `Fun` gives it
+/// one, which is all [Shr] needs to pick the right impl.
+/// ```
+/// # use chained::*;
+/// let result = (Link::new(10) >> Fun(|x| x + 1) >> Fun(|x| x * x)).eval();
+/// assert_eq!(121, result);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Fun<F>(pub F);
+
+impl<T, F, U> Shr<Fun<F>> for Link<T>
+where
+    F: FnOnce(T) -> U,
+{
+    type Output = Chain<Self, F, U>;
+    fn shr(self, rhs: Fun<F>) -> Self::Output {
+        self.chain(rhs.0)
+    }
+}
+
+impl<C, FPrev, TPrev, F, U> Shr<Fun<F>> for Chain<C, FPrev, TPrev>
+where
+    C: Chained,
+    FPrev: FnOnce(C::Item) -> TPrev,
+    F: FnOnce(TPrev) -> U,
+{
+    type Output = Chain<Self, F, U>;
+    fn shr(self, rhs: Fun<F>) -> Self::Output {
+        self.chain(rhs.0)
+    }
+}
+
+/// A fallible counterpart to [Chained], for chains whose stages can fail.
+///
+/// Where [Chained::eval] always produces a value, [TryChained::try_eval] threads the value
+/// through each stored closure and returns as soon as one of them returns `Err`, much like
+/// the `?` operator does for a single call.
+pub trait TryChained {
+    type Ok;
+    type Err;
+    fn try_eval(self) -> Result<Self::Ok, Self::Err>;
+
+    fn try_chain<F, U>(self, fun: F) -> TryChain<Self, F, U, Self::Err>
+    where
+        Self: Sized,
+        F: FnOnce(Self::Ok) -> Result<U, Self::Err>,
+    {
+        TryChain { val: self, fun }
+    }
+}
+
+/// The base type which implements the [TryChained] trait. It holds the initial `Result` and is
+/// always the starting point of a fallible chain.
+///
+/// Mirrors [Link], except it seeds the chain with a `Result<T, E>` instead of a bare value, so
+/// the first `try_chain` call has something to short-circuit on.
+/// ```
+/// # use chained::*;
+/// let x: Result<i32, &str> = TryLink::new(Ok(10)).try_chain(|x| Ok(x + x)).try_eval();
+/// assert_eq!(Ok(20), x);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TryLink<T, E>(Result<T, E>);
+
+impl<T, E> TryLink<T, E> {
+    pub fn new(val: Result<T, E>) -> Self {
+        TryLink(val)
+    }
+}
+
+impl<T, E> From<Result<T, E>> for TryLink<T, E> {
+    fn from(value: Result<T, E>) -> Self {
+        TryLink::new(value)
+    }
+}
+
+impl<T, E> TryChained for TryLink<T, E> {
+    type Ok = T;
+    type Err = E;
+    fn try_eval(self) -> Result<Self::Ok, Self::Err> {
+        self.0
+    }
+}
+
+/// The type that is returned when the [TryChained::try_chain] method is called.
+///
+/// TryChain implements the [TryChained] trait and stores the previous chain or `Result`, and a
+/// fallible function. This struct is analogous to [Chain], except `try_eval` stops at the first
+/// stage that returns `Err`.
+#[derive(Clone)]
+pub struct TryChain<C: TryChained, F, T, E>
+where
+    F: FnOnce(C::Ok) -> Result<T, E>,
+{
+    val: C,
+    fun: F,
+}
+
+impl<C, F, T, E, B> TryChained for TryChain<C, F, T, E>
+where
+    C: TryChained<Ok = B, Err = E>,
+    F: FnOnce(C::Ok) -> Result<T, E>,
+{
+    type Ok = T;
+    type Err = E;
+    fn try_eval(self) -> Result<Self::Ok, Self::Err> {
+        let value = self.val.try_eval()?;
+        (self.fun)(value)
+    }
+}
+
+/// A re-evaluable counterpart to [Chained], built on [Fn] instead of [FnOnce].
+///
+/// Every [Chained] chain is one-shot: `eval` consumes it, so the same pipeline can't be run
+/// twice or have its result cached. A [ChainedRef] chain instead keeps its stages as `Fn` and
+/// its base value as [Clone], so [ChainedRef::eval_ref] can be called any number of times on a
+/// shared reference.
+pub trait ChainedRef {
+    type Item;
+    fn eval_ref(&self) -> Self::Item;
+
+    fn chain_ref<F, T>(self, fun: F) -> ChainRef<Self, F, T>
+    where
+        Self: Sized,
+        F: Fn(Self::Item) -> T,
+    {
+        ChainRef { val: self, fun }
+    }
+
+    /// Wraps this chain so its result is computed once and cached on every later
+    /// [eval_ref][ChainedRef::eval_ref] call.
+    ///
+    /// This is only sound for side-effect-free closures: `memoize` assumes every stage is a
+    /// pure function, so returning a cached clone of the first result is indistinguishable
+    /// from re-running the chain.
+    fn memoize(self) -> Memoize<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Memoize::new(self)
+    }
+}
+
+/// The base type which implements the [ChainedRef] trait. It holds the initial value and is
+/// always the starting point of a re-evaluable chain.
+///
+/// Mirrors [Link], except `eval_ref` takes `&self` and clones the stored value out instead of
+/// consuming it, which is what lets the chain built on top of it run more than once.
+/// ```
+/// # use chained::*;
+/// let link = LinkRef::new(10);
+/// assert_eq!(10, link.eval_ref());
+/// assert_eq!(10, link.eval_ref());
+/// ```
+#[derive(Clone, Debug)]
+pub struct LinkRef<T>(T);
+
+impl<T> LinkRef<T> {
+    pub fn new(val: T) -> Self {
+        LinkRef(val)
+    }
+}
+
+impl<T> From<T> for LinkRef<T> {
+    fn from(value: T) -> Self {
+        LinkRef::new(value)
+    }
+}
+
+impl<T: Clone> ChainedRef for LinkRef<T> {
+    type Item = T;
+    fn eval_ref(&self) -> Self::Item {
+        self.0.clone()
+    }
+}
+
+/// The type that is returned when the [ChainedRef::chain_ref] method is called.
+///
+/// ChainRef implements the [ChainedRef] trait and stores the previous chain or value, and a
+/// function. This struct is analogous to [Chain], except `eval_ref` borrows instead of
+/// consuming, so the same [ChainRef] can be evaluated repeatedly.
+#[derive(Clone)]
+pub struct ChainRef<C: ChainedRef, F, T>
+where
+    F: Fn(C::Item) -> T,
+{
+    val: C,
+    fun: F,
+}
+
+impl<C, F, T, B> ChainedRef for ChainRef<C, F, T>
+where
+    C: ChainedRef<Item = B>,
+    F: Fn(C::Item) -> T,
+{
+    type Item = T;
+    fn eval_ref(&self) -> Self::Item {
+        (self.fun)(self.val.eval_ref())
+    }
+}
+
+/// The type returned by [ChainedRef::memoize].
+///
+/// Holds the wrapped chain alongside a [OnceCell] that caches the result of the first
+/// [eval_ref][ChainedRef::eval_ref] call; every subsequent call returns a clone of the cached
+/// value instead of re-running the chain.
+pub struct Memoize<C: ChainedRef> {
+    chain: C,
+    cache: OnceCell<C::Item>,
+}
+
+impl<C: ChainedRef> Memoize<C> {
+    pub fn new(chain: C) -> Self {
+        Memoize {
+            chain,
+            cache: OnceCell::new(),
+        }
+    }
+}
+
+impl<C> ChainedRef for Memoize<C>
+where
+    C: ChainedRef,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+    fn eval_ref(&self) -> Self::Item {
+        self.cache.get_or_init(|| self.chain.eval_ref()).clone()
+    }
+}
+
+/// Implemented for tuples of closures that [Chained::fork] can run a cloned value through.
+///
+/// Each closure receives its own clone of the upstream value, except the last one, which takes
+/// the value by move since nothing downstream needs another clone.
+pub trait ForkFns<Item> {
+    type Out;
+    fn call_each(self, item: Item) -> Self::Out;
+}
+
+impl<Item, F1, T1, F2, T2> ForkFns<Item> for (F1, F2)
+where
+    Item: Clone,
+    F1: FnOnce(Item) -> T1,
+    F2: FnOnce(Item) -> T2,
+{
+    type Out = (T1, T2);
+    fn call_each(self, item: Item) -> Self::Out {
+        let t1 = (self.0)(item.clone());
+        let t2 = (self.1)(item);
+        (t1, t2)
+    }
+}
+
+impl<Item, F1, T1, F2, T2, F3, T3> ForkFns<Item> for (F1, F2, F3)
+where
+    Item: Clone,
+    F1: FnOnce(Item) -> T1,
+    F2: FnOnce(Item) -> T2,
+    F3: FnOnce(Item) -> T3,
+{
+    type Out = (T1, T2, T3);
+    fn call_each(self, item: Item) -> Self::Out {
+        let t1 = (self.0)(item.clone());
+        let t2 = (self.1)(item.clone());
+        let t3 = (self.2)(item);
+        (t1, t2, t3)
+    }
+}
+
+impl<Item, F1, T1, F2, T2, F3, T3, F4, T4> ForkFns<Item> for (F1, F2, F3, F4)
+where
+    Item: Clone,
+    F1: FnOnce(Item) -> T1,
+    F2: FnOnce(Item) -> T2,
+    F3: FnOnce(Item) -> T3,
+    F4: FnOnce(Item) -> T4,
+{
+    type Out = (T1, T2, T3, T4);
+    fn call_each(self, item: Item) -> Self::Out {
+        let t1 = (self.0)(item.clone());
+        let t2 = (self.1)(item.clone());
+        let t3 = (self.2)(item.clone());
+        let t4 = (self.3)(item);
+        (t1, t2, t3, t4)
+    }
+}
+
+/// The type returned by [Chained::fork].
+///
+/// Fork implements the [Chained] trait and stores the upstream chain plus a tuple of
+/// closures. On [eval][Chained::eval] the upstream value is cloned once per closure (bar the
+/// last) and each closure gets its own copy, producing a tuple of outputs.
+#[derive(Clone)]
+pub struct Fork<C, Fns> {
+    val: C,
+    funs: Fns,
+}
+
+impl<C, Fns> Chained for Fork<C, Fns>
+where
+    C: Chained,
+    C::Item: Clone,
+    Fns: ForkFns<C::Item>,
+{
+    type Item = Fns::Out;
+    fn eval(self) -> Self::Item {
+        self.funs.call_each(self.val.eval())
+    }
+}
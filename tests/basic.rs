@@ -107,6 +107,168 @@ mod inter_chain {
     }
 }
 
+mod shr_operator {
+    use chained::*;
+
+    #[test]
+    fn single_stage() {
+        let result = (Link::new(10) >> Fun(|x| x + 1)).eval();
+        assert_eq!(11, result);
+    }
+
+    #[test]
+    fn multiple_stages() {
+        let result = (Link::new(10) >> Fun(|x| x + 1) >> Fun(|x| x * x)).eval();
+        assert_eq!(121, result);
+    }
+
+    #[test]
+    fn matches_chain_method() {
+        let via_shr = (Link::new(5) >> Fun(|x| x + 1) >> Fun(|x| x * 2)).eval();
+        let via_chain = Link::new(5).chain(|x| x + 1).chain(|x| x * 2).eval();
+        assert_eq!(via_shr, via_chain);
+    }
+}
+
+mod fork {
+    use chained::*;
+
+    #[test]
+    fn two_way() {
+        let (sum, product) = Link::new(4).fork((|x| x + x, |x| x * x)).eval();
+        assert_eq!((8, 16), (sum, product));
+    }
+
+    #[test]
+    fn three_way() {
+        let result = Link::new(3)
+            .fork((|x| x + 1, |x| x * 2, |x: i32| x.to_string()))
+            .eval();
+        assert_eq!((4, 6, "3".to_owned()), result);
+    }
+
+    #[test]
+    fn after_chain() {
+        let (doubled, stringified) = Link::new(10)
+            .chain(|x| x + 5)
+            .fork((|x| x * 2, |x: i32| x.to_string()))
+            .eval();
+        assert_eq!(30, doubled);
+        assert_eq!("15", stringified);
+    }
+
+    #[test]
+    fn macro_inline() {
+        let (sum, product) = chained!(4; fork[|x| x + x, |x| x * x]).eval();
+        assert_eq!((8, 16), (sum, product));
+    }
+
+    #[test]
+    fn macro_inline_after_stage() {
+        let (doubled, stringified) =
+            chained!(10, |x| x + 5; fork[|x| x * 2, |x: i32| x.to_string()]).eval();
+        assert_eq!(30, doubled);
+        assert_eq!("15", stringified);
+    }
+}
+
+mod inspect {
+    use chained::*;
+
+    #[test]
+    fn passes_value_through() {
+        let mut seen = None;
+        let result = Link::new(10)
+            .inspect(|x| seen = Some(*x))
+            .chain(|x| x + 1)
+            .eval();
+        assert_eq!(Some(10), seen);
+        assert_eq!(11, result);
+    }
+}
+
+mod chain_ref {
+    use std::cell::Cell;
+
+    use chained::*;
+
+    #[test]
+    fn reusable() {
+        let chain = LinkRef::new(10).chain_ref(|x| x + 1).chain_ref(|x| x * x);
+        assert_eq!(121, chain.eval_ref());
+        assert_eq!(121, chain.eval_ref());
+    }
+
+    #[test]
+    fn recomputes_without_memoize() {
+        let calls = Cell::new(0);
+        let chain = LinkRef::new(10).chain_ref(|x| {
+            calls.set(calls.get() + 1);
+            x + 1
+        });
+        chain.eval_ref();
+        chain.eval_ref();
+        assert_eq!(2, calls.get());
+    }
+
+    #[test]
+    fn memoize_caches_result() {
+        let calls = Cell::new(0);
+        let chain = LinkRef::new(10)
+            .chain_ref(|x| {
+                calls.set(calls.get() + 1);
+                x + 1
+            })
+            .memoize();
+        assert_eq!(11, chain.eval_ref());
+        assert_eq!(11, chain.eval_ref());
+        assert_eq!(1, calls.get());
+    }
+}
+
+mod try_chain {
+    use chained::*;
+
+    #[test]
+    fn ok_path() {
+        let result = TryLink::new(Ok::<_, &str>(10))
+            .try_chain(|x| Ok(x + 10))
+            .try_chain(|x| Ok(x * 2))
+            .try_eval();
+        assert_eq!(Ok(40), result);
+    }
+
+    #[test]
+    fn short_circuits_on_first_err() {
+        let mut ran = false;
+        let result = TryLink::new(Ok::<i32, &str>(10))
+            .try_chain(|_| Err("failed"))
+            .try_chain(|x: i32| {
+                ran = true;
+                Ok(x)
+            })
+            .try_eval();
+        assert_eq!(Err("failed"), result);
+        assert!(!ran);
+    }
+
+    #[test]
+    fn macro_lazy() {
+        let chain = chained!(? Ok::<_, &str>("42"), |s: &str| s
+            .parse::<i32>()
+            .map_err(|_| "bad number"));
+        assert_eq!(Ok(42), chain.try_eval());
+    }
+
+    #[test]
+    fn macro_propagates_err() {
+        let chain = chained!(? Ok::<_, &str>("nope"), |s: &str| s
+            .parse::<i32>()
+            .map_err(|_| "bad number"));
+        assert_eq!(Err("bad number"), chain.try_eval());
+    }
+}
+
 mod standalone_macro {
     use chained::chained;
 